@@ -1,4 +1,5 @@
 use core::fmt;
+use std::path::PathBuf;
 
 use clap::Parser;
 
@@ -31,16 +32,58 @@ pub struct Args {
     #[arg(short, long, default_value_t, value_enum)]
     pub starting_graph: ArgsGraphType,
 
+    /// Path to an adjacency-matrix or edge-list file, required when `starting_graph` is `from-file`
+    #[arg(long)]
+    pub starting_graph_file: Option<PathBuf>,
+
+    /// Edge inclusion probability, required when `starting_graph` is `gnp`
+    #[arg(long)]
+    pub seed_edge_prob: Option<f64>,
+
+    /// Exact number of seed edges, required when `starting_graph` is `gnm`
+    #[arg(long)]
+    pub seed_edge_count: Option<usize>,
+
+    /// Seed for the simulation RNG, shared across all iterations. Runs with the same seed and
+    /// arguments are reproducible.
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Metric recorded over time for the tracked vertices
+    #[arg(long, default_value_t, value_enum)]
+    pub track_metric: ArgsTrackMetric,
+
+    /// Compute average clustering coefficient, connected components, average shortest path
+    /// length and diameter for each iteration. Off by default: the shortest-path/diameter pass
+    /// is a full BFS from every node (O(V*(V+E)) per iteration), which dwarfs the rest of the
+    /// simulation on the large graphs this tool defaults to.
+    #[arg(long, default_value_t = false)]
+    pub network_metrics: bool,
+
     #[arg(long, value_enum)]
     pub model: ArgsModelType,
 }
 
+#[derive(clap::ValueEnum, Debug, Clone, Default, Copy, PartialEq, Eq)]
+#[value(rename_all = "snake_case")]
+pub enum ArgsTrackMetric {
+    #[default]
+    Degree,
+    ClusteringCoefficient,
+    ComponentSize,
+}
+
 #[derive(clap::ValueEnum, Debug, Clone, Default, Copy, PartialEq, Eq)]
 pub enum ArgsGraphType {
     #[default]
     Complete,
     Star,
     Disconnected,
+    FromFile,
+    /// Erdős–Rényi G(n, p): each possible edge included independently with probability `p`
+    Gnp,
+    /// Erdős–Rényi G(n, m): exactly `m` edges chosen uniformly without replacement
+    Gnm,
 }
 
 #[derive(clap::ValueEnum, Debug, Clone, Copy)]
@@ -0,0 +1,94 @@
+/// A packed bit vector used for O(1) membership tests, backed by `u64` words.
+///
+/// Bit `i` lives in word `i >> 6` at mask `1 << (i & 63)`.
+#[derive(Debug, Clone)]
+pub struct PackedBitSet {
+    words: Vec<u64>,
+}
+
+impl PackedBitSet {
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0u64; len.div_ceil(64)],
+        }
+    }
+
+    pub fn set(&mut self, index: usize) {
+        self.words[index >> 6] |= 1 << (index & 63);
+    }
+
+    pub fn clear(&mut self, index: usize) {
+        self.words[index >> 6] &= !(1 << (index & 63));
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.words[index >> 6] & (1 << (index & 63)) != 0
+    }
+}
+
+/// A packed bit matrix used as an adjacency lookup, backed by `u64` words.
+///
+/// Row `r` starts at word `r * words_per_row`, so `contains(r, c)` is a single
+/// word-and-mask lookup instead of a petgraph edge scan.
+#[derive(Debug, Clone)]
+pub struct PackedBitMatrix {
+    words: Vec<u64>,
+    words_per_row: usize,
+}
+
+impl PackedBitMatrix {
+    pub fn new(elements: usize, n_max: usize) -> Self {
+        let words_per_row = n_max.div_ceil(64);
+        Self {
+            words: vec![0u64; elements * words_per_row],
+            words_per_row,
+        }
+    }
+
+    pub fn set(&mut self, row: usize, col: usize) {
+        let index = row * self.words_per_row + (col >> 6);
+        self.words[index] |= 1 << (col & 63);
+    }
+
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        let index = row * self.words_per_row + (col >> 6);
+        self.words[index] & (1 << (col & 63)) != 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{PackedBitMatrix, PackedBitSet};
+
+    #[test]
+    fn test_packed_bitset_set_contains_clear() {
+        let mut set = PackedBitSet::new(200);
+        assert!(!set.contains(130));
+        set.set(130);
+        assert!(set.contains(130));
+        set.clear(130);
+        assert!(!set.contains(130));
+    }
+
+    #[test]
+    fn test_packed_bitset_words_are_independent() {
+        let mut set = PackedBitSet::new(128);
+        set.set(0);
+        set.set(64);
+        assert!(set.contains(0));
+        assert!(set.contains(64));
+        assert!(!set.contains(1));
+        assert!(!set.contains(65));
+    }
+
+    #[test]
+    fn test_packed_bit_matrix_set_contains_symmetric() {
+        let mut matrix = PackedBitMatrix::new(10, 10);
+        assert!(!matrix.contains(2, 7));
+        matrix.set(2, 7);
+        matrix.set(7, 2);
+        assert!(matrix.contains(2, 7));
+        assert!(matrix.contains(7, 2));
+        assert!(!matrix.contains(2, 8));
+    }
+}
@@ -1,10 +1,13 @@
-use std::{fmt::Debug, fs, path::Path};
+use std::{collections::HashMap, fmt::Debug, fs, path::Path};
 
 use petgraph::{
     dot::{Config, Dot},
+    graph::{NodeIndex, UnGraph},
     EdgeType, Graph,
 };
 
+use crate::{power_law::PowerLawFit, simulation::MeanNetworkMetrics};
+
 pub fn write_dot_to_file<N, E, Ty, P: AsRef<Path>>(
     graph: Graph<N, E, Ty>,
     path: P,
@@ -28,3 +31,198 @@ pub fn write_values_to_file<P: AsRef<Path>>(values: Vec<usize>, path: P) -> anyh
     fs::write(path, line)?;
     Ok(())
 }
+
+pub fn write_f64_values_to_file<P: AsRef<Path>>(values: Vec<f64>, path: P) -> anyhow::Result<()> {
+    let line = values
+        .iter()
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    fs::write(path, line)?;
+    Ok(())
+}
+
+/// Write the result of a power-law fit as `key=value` lines, so users can confirm the
+/// expected scaling of a model's degree sequence without re-running the analysis.
+pub fn write_power_law_fit_to_file<P: AsRef<Path>>(
+    fit: &PowerLawFit,
+    path: P,
+) -> anyhow::Result<()> {
+    let content = format!(
+        "k_min={}\nalpha={}\nstandard_error={}\nks_distance={}\n",
+        fit.k_min, fit.alpha, fit.standard_error, fit.ks_distance
+    );
+    fs::write(path, content)?;
+    Ok(())
+}
+
+/// Write the result of a `NetworkMetrics` averaging pass as `key=value` lines, so users can
+/// confirm a model produces short paths with low clustering without re-running the analysis.
+pub fn write_network_metrics_to_file<P: AsRef<Path>>(
+    metrics: &MeanNetworkMetrics,
+    path: P,
+) -> anyhow::Result<()> {
+    let content = format!(
+        "average_clustering_coefficient={}\nconnected_components_count={}\naverage_shortest_path_length={}\ndiameter={}\n",
+        metrics.average_clustering_coefficient,
+        metrics.connected_components_count,
+        metrics.average_shortest_path_length,
+        metrics.diameter
+    );
+    fs::write(path, content)?;
+    Ok(())
+}
+
+/// Read a starting graph from `path`, accepting either a square symmetric adjacency
+/// matrix (rows of `0`/`1` tokens) or a plain edge list (`u v` per line). `expected_node_count`
+/// must match the loaded graph's node count, since every downstream size (bitset/adjacency
+/// capacities, tracked-arrival indices, `initial_uniform`) is derived from `-n` and assumes the
+/// starting graph has exactly that many vertices.
+pub fn read_graph_from_file<P: AsRef<Path>>(
+    path: P,
+    expected_node_count: usize,
+) -> anyhow::Result<UnGraph<(), ()>> {
+    let content = fs::read_to_string(path)?;
+    let rows: Vec<Vec<&str>> = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.split_whitespace().collect())
+        .collect();
+
+    let graph = if rows.is_empty() {
+        UnGraph::new_undirected()
+    } else {
+        let is_adjacency_matrix = rows.len() > 1
+            && rows.iter().all(|row| row.len() == rows.len())
+            && rows
+                .iter()
+                .all(|row| row.iter().all(|token| *token == "0" || *token == "1"));
+
+        if is_adjacency_matrix {
+            read_adjacency_matrix(&rows)?
+        } else {
+            read_edge_list(&rows)?
+        }
+    };
+
+    anyhow::ensure!(
+        graph.node_count() == expected_node_count,
+        "starting graph file has {} nodes, expected {expected_node_count} (`-n`)",
+        graph.node_count()
+    );
+    Ok(graph)
+}
+
+fn read_adjacency_matrix(rows: &[Vec<&str>]) -> anyhow::Result<UnGraph<(), ()>> {
+    let n = rows.len();
+    let mut graph = UnGraph::<(), ()>::new_undirected();
+    let nodes: Vec<NodeIndex> = (0..n).map(|_| graph.add_node(())).collect();
+
+    for row in 0..n {
+        for col in (row + 1)..n {
+            let value: u8 = rows[row][col].parse()?;
+            let symmetric_value: u8 = rows[col][row].parse()?;
+            anyhow::ensure!(
+                value == symmetric_value,
+                "adjacency matrix is not symmetric at ({row}, {col})"
+            );
+            if value == 1 {
+                graph.add_edge(nodes[row], nodes[col], ());
+            }
+        }
+    }
+    Ok(graph)
+}
+
+fn read_edge_list(rows: &[Vec<&str>]) -> anyhow::Result<UnGraph<(), ()>> {
+    let mut graph = UnGraph::<(), ()>::new_undirected();
+    let mut nodes: HashMap<usize, NodeIndex> = HashMap::new();
+
+    for row in rows {
+        anyhow::ensure!(
+            row.len() == 2,
+            "edge list lines must contain exactly two node ids, got {row:?}"
+        );
+        let u: usize = row[0].parse()?;
+        let v: usize = row[1].parse()?;
+        let u_index = *nodes.entry(u).or_insert_with(|| graph.add_node(()));
+        let v_index = *nodes.entry(v).or_insert_with(|| graph.add_node(()));
+        graph.add_edge(u_index, v_index, ());
+    }
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::read_graph_from_file;
+
+    /// Write `content` to a fresh temp file and return its path, so each test gets its own
+    /// file instead of racing on a shared name.
+    fn write_temp_file(name: &str, content: &str) -> std::path::PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("barabasi_albert_simulation_{name}_{id}"));
+        std::fs::write(&path, content).expect("failed to write temp fixture file");
+        path
+    }
+
+    #[test]
+    fn test_read_graph_from_file_parses_adjacency_matrix() {
+        let path = write_temp_file("adjacency_matrix", "0 1 0\n1 0 1\n0 1 0\n");
+        let graph = read_graph_from_file(&path, 3).expect("should parse as adjacency matrix");
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_count(), 2);
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_read_graph_from_file_rejects_asymmetric_adjacency_matrix() {
+        let path = write_temp_file("asymmetric_matrix", "0 1\n0 0\n");
+        let result = read_graph_from_file(&path, 2);
+
+        assert!(result.is_err(), "an asymmetric matrix should be rejected");
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_read_graph_from_file_parses_edge_list() {
+        // Node ids above 1 rule out the adjacency-matrix sniff (tokens must all be "0"/"1"),
+        // so this unambiguously takes the edge-list path.
+        let path = write_temp_file("edge_list", "0 2\n2 3\n");
+        let graph = read_graph_from_file(&path, 3).expect("should parse as an edge list");
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_count(), 2);
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_read_graph_from_file_rejects_node_count_mismatch() {
+        let path = write_temp_file("node_count_mismatch", "0 2\n2 3\n");
+        let result = read_graph_from_file(&path, 5);
+
+        assert!(
+            result.is_err(),
+            "a graph with fewer nodes than `-n` should be rejected"
+        );
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_read_graph_from_file_ambiguous_two_node_edge_list_is_sniffed_as_adjacency_matrix() {
+        // A 2-line, 2-token-per-line edge list using only node ids 0 and 1 is indistinguishable
+        // from a 2x2 adjacency matrix by the current sniffing heuristic (every token is "0" or
+        // "1"), so it is read as the matrix `[[0, 1], [1, 0]]` (one edge) rather than as the two
+        // edge-list rows `0-1` and `1-0` (which the edge-list path would add as two parallel
+        // edges). This test documents that known ambiguity.
+        let path = write_temp_file("ambiguous_edge_list", "0 1\n1 0\n");
+        let graph = read_graph_from_file(&path, 2).expect("should parse as adjacency matrix");
+
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.edge_count(), 1);
+        let _ = std::fs::remove_file(path);
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::{HashMap, HashSet};
+
+use petgraph::graph::{NodeIndex, UnGraph};
+use rand::{distributions::Uniform, prelude::Distribution, Rng};
+
+/// An abstraction over incremental graph construction, so starting-graph generators can
+/// add edges between numeric vertex ids without caring how the underlying `UnGraph` is built.
+pub trait Builder {
+    fn add_edge(&mut self, u: usize, v: usize);
+    fn finalize(self) -> UnGraph<(), ()>;
+}
+
+/// A `Builder` that lazily allocates a `NodeIndex` for each vertex id it sees.
+pub struct GraphBuilder {
+    graph: UnGraph<(), ()>,
+    nodes: HashMap<usize, NodeIndex>,
+}
+
+impl GraphBuilder {
+    pub fn new() -> Self {
+        Self {
+            graph: UnGraph::new_undirected(),
+            nodes: HashMap::new(),
+        }
+    }
+
+    /// Pre-allocate `node_count` vertices, numbered `0..node_count`, even if some end up isolated.
+    pub fn with_nodes(node_count: usize) -> Self {
+        let mut builder = Self::new();
+        for id in 0..node_count {
+            builder.node(id);
+        }
+        builder
+    }
+
+    fn node(&mut self, id: usize) -> NodeIndex {
+        if let Some(&index) = self.nodes.get(&id) {
+            return index;
+        }
+        let index = self.graph.add_node(());
+        self.nodes.insert(id, index);
+        index
+    }
+}
+
+impl Default for GraphBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Builder for GraphBuilder {
+    fn add_edge(&mut self, u: usize, v: usize) {
+        let u_index = self.node(u);
+        let v_index = self.node(v);
+        if self.graph.find_edge(u_index, v_index).is_none() {
+            self.graph.add_edge(u_index, v_index, ());
+        }
+    }
+
+    fn finalize(self) -> UnGraph<(), ()> {
+        self.graph
+    }
+}
+
+/// Erdős–Rényi G(n, p): each of the `n*(n-1)/2` possible edges is included independently
+/// with probability `p`.
+pub fn gnp_graph<R: Rng>(n: usize, p: f64, rng: &mut R) -> UnGraph<(), ()> {
+    let mut builder = GraphBuilder::with_nodes(n);
+    for u in 0..n {
+        for v in (u + 1)..n {
+            if rng.gen::<f64>() < p {
+                builder.add_edge(u, v);
+            }
+        }
+    }
+    builder.finalize()
+}
+
+/// Erdős–Rényi G(n, m): exactly `m` edges chosen uniformly without replacement.
+pub fn gnm_graph<R: Rng>(n: usize, m: usize, rng: &mut R) -> UnGraph<(), ()> {
+    let max_edges = n * (n - 1) / 2;
+    assert!(
+        m <= max_edges,
+        "seed edge count {m} exceeds the {max_edges} possible edges for {n} nodes"
+    );
+
+    let mut builder = GraphBuilder::with_nodes(n);
+    let mut chosen: HashSet<(usize, usize)> = HashSet::new();
+    let uniform = Uniform::new(0, n);
+
+    while chosen.len() < m {
+        let u = uniform.sample(rng);
+        let v = uniform.sample(rng);
+        if u == v {
+            continue;
+        }
+        let key = if u < v { (u, v) } else { (v, u) };
+        if chosen.insert(key) {
+            builder.add_edge(key.0, key.1);
+        }
+    }
+    builder.finalize()
+}
+
+#[cfg(test)]
+mod test {
+    use rand::thread_rng;
+
+    use super::{gnm_graph, gnp_graph};
+    use crate::graph_utils::DegreeSequence;
+
+    #[test]
+    fn test_gnp_graph_node_count() {
+        let graph = gnp_graph(10, 0.5, &mut thread_rng());
+        assert_eq!(graph.node_count(), 10);
+    }
+
+    #[test]
+    fn test_gnp_graph_no_edges_when_p_is_zero() {
+        let graph = gnp_graph(10, 0.0, &mut thread_rng());
+        assert_eq!(graph.edge_count(), 0);
+    }
+
+    #[test]
+    fn test_gnp_graph_complete_when_p_is_one() {
+        let graph = gnp_graph(6, 1.0, &mut thread_rng());
+        assert_eq!(graph.edge_count(), (6 * 5) / 2);
+    }
+
+    #[test]
+    fn test_gnm_graph_exact_edge_count() {
+        let graph = gnm_graph(8, 10, &mut thread_rng());
+        assert_eq!(graph.node_count(), 8);
+        assert_eq!(graph.edge_count(), 10);
+    }
+
+    #[test]
+    fn test_gnm_graph_degree_sequence_sum() {
+        let graph = gnm_graph(8, 10, &mut thread_rng());
+        let degree_sum: usize = graph.degree_sequence().iter().sum();
+        assert_eq!(degree_sum, 2 * 10);
+    }
+}
@@ -1,4 +1,12 @@
-use petgraph::graph::UnGraph;
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+};
+
+use petgraph::{
+    dot::{Config, Dot},
+    graph::{NodeIndex, UnGraph},
+};
 
 /// A Model that is able to compute it's degree sequence
 pub trait DegreeSequence {
@@ -9,6 +17,26 @@ pub trait Complete {
     fn is_complete(&self) -> bool;
 }
 
+/// A graph that can render itself as Graphviz DOT, styling each node by its own degree so the
+/// hubs produced by preferential attachment are visually obvious without external post-processing.
+pub trait DotExport {
+    fn to_dot(&self) -> String;
+}
+
+/// Structural metrics used to confirm a network exhibits scale-free / small-world properties:
+/// short average path length paired with low clustering.
+pub trait NetworkMetrics {
+    /// Mean of the local clustering coefficient `c_i = 2 * (#edges among neighbors) / (k_i * (k_i - 1))`
+    /// over every vertex.
+    fn average_clustering_coefficient(&self) -> f64;
+    /// Number of connected components.
+    fn connected_components_count(&self) -> usize;
+    /// Mean shortest-path length over every pair of vertices reachable from one another.
+    fn average_shortest_path_length(&self) -> f64;
+    /// Length of the longest shortest path between any two reachable vertices.
+    fn diameter(&self) -> usize;
+}
+
 impl<N, E> DegreeSequence for UnGraph<N, E> {
     fn degree_sequence(&self) -> Vec<usize> {
         let mut out = vec![];
@@ -28,9 +56,131 @@ impl<N, E> Complete for UnGraph<N, E> {
     }
 }
 
+impl<N, E> DotExport for UnGraph<N, E>
+where
+    N: Debug,
+    E: Debug,
+{
+    fn to_dot(&self) -> String {
+        let edge_attrs = |_, _| String::new();
+        let node_attrs = |_, (node, _): (NodeIndex, &N)| {
+            let degree = self.edges(node).count();
+            let size = 0.3 + degree as f64 * 0.1;
+            format!(
+                "style=filled, fillcolor=\"{}\", width={size}, height={size}",
+                degree_color(degree)
+            )
+        };
+        let dot = Dot::with_attr_getters(
+            self,
+            &[Config::EdgeNoLabel, Config::NodeNoLabel],
+            &edge_attrs,
+            &node_attrs,
+        );
+        format!("{:?}", dot)
+    }
+}
+
+impl<N, E> NetworkMetrics for UnGraph<N, E> {
+    fn average_clustering_coefficient(&self) -> f64 {
+        if self.node_count() == 0 {
+            return 0.0;
+        }
+        let sum: f64 = self
+            .node_indices()
+            .map(|node| local_clustering_coefficient(self, node))
+            .sum();
+        sum / self.node_count() as f64
+    }
+
+    fn connected_components_count(&self) -> usize {
+        petgraph::algo::connected_components(self)
+    }
+
+    fn average_shortest_path_length(&self) -> f64 {
+        let (total_distance, pair_count, _) = shortest_path_stats(self);
+        if pair_count == 0 {
+            return 0.0;
+        }
+        total_distance as f64 / pair_count as f64
+    }
+
+    fn diameter(&self) -> usize {
+        let (_, _, max_distance) = shortest_path_stats(self);
+        max_distance
+    }
+}
+
+fn local_clustering_coefficient<N, E>(graph: &UnGraph<N, E>, vertex: NodeIndex) -> f64 {
+    let neighbors: Vec<NodeIndex> = graph.neighbors(vertex).collect();
+    let k = neighbors.len();
+    if k < 2 {
+        return 0.0;
+    }
+
+    let mut links = 0usize;
+    for i in 0..neighbors.len() {
+        for j in (i + 1)..neighbors.len() {
+            if graph.find_edge(neighbors[i], neighbors[j]).is_some() {
+                links += 1;
+            }
+        }
+    }
+
+    (2 * links) as f64 / (k * (k - 1)) as f64
+}
+
+/// BFS from every vertex, accumulating the sum of shortest-path lengths and the number of
+/// ordered pairs reached over the whole graph, alongside the longest shortest path found.
+fn shortest_path_stats<N, E>(graph: &UnGraph<N, E>) -> (usize, usize, usize) {
+    let mut total_distance = 0usize;
+    let mut pair_count = 0usize;
+    let mut max_distance = 0usize;
+
+    for source in graph.node_indices() {
+        let mut distances: HashMap<NodeIndex, usize> = HashMap::new();
+        let mut queue = VecDeque::new();
+        distances.insert(source, 0);
+        queue.push_back(source);
+
+        while let Some(current) = queue.pop_front() {
+            let distance = distances[&current];
+            for neighbor in graph.neighbors(current) {
+                if distances.contains_key(&neighbor) {
+                    continue;
+                }
+                distances.insert(neighbor, distance + 1);
+                queue.push_back(neighbor);
+            }
+        }
+
+        for (&node, &distance) in &distances {
+            if node == source {
+                continue;
+            }
+            total_distance += distance;
+            pair_count += 1;
+            max_distance = max_distance.max(distance);
+        }
+    }
+
+    (total_distance, pair_count, max_distance)
+}
+
+/// Bucket a node's degree into a blue color ramp, so hubs stand out darker than leaves when
+/// the DOT output is rendered.
+fn degree_color(degree: usize) -> &'static str {
+    match degree {
+        0..=1 => "#deebf7",
+        2..=4 => "#9ecae1",
+        5..=9 => "#4292c6",
+        _ => "#08306b",
+    }
+}
+
 #[cfg(test)]
 pub mod test {
-    use crate::graph_utils::DegreeSequence;
+    use crate::graph_utils::{DegreeSequence, DotExport, NetworkMetrics};
 
     #[test]
     fn test_degree_sequence_empty_graph() {
@@ -166,4 +316,58 @@ pub mod test {
             "Sum of degree sequence should be twice the number of edges"
         );
     }
+
+    #[test]
+    fn test_to_dot_contains_a_node_per_vertex() {
+        use petgraph::graph::UnGraph;
+
+        let mut graph: UnGraph<(), ()> = UnGraph::new_undirected();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        graph.add_edge(a, b, ());
+
+        let dot = graph.to_dot();
+
+        assert_eq!(dot.matches("style=filled").count(), 2);
+    }
+
+    #[test]
+    fn test_connected_components_count_disjoint_pairs() {
+        use petgraph::graph::UnGraph;
+
+        let mut graph: UnGraph<(), ()> = UnGraph::new_undirected();
+        let nodes: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+        graph.add_edge(nodes[0], nodes[1], ());
+        graph.add_edge(nodes[2], nodes[3], ());
+
+        assert_eq!(graph.connected_components_count(), 2);
+    }
+
+    #[test]
+    fn test_average_clustering_coefficient_triangle() {
+        use petgraph::graph::UnGraph;
+
+        let mut graph: UnGraph<(), ()> = UnGraph::new_undirected();
+        let nodes: Vec<_> = (0..3).map(|_| graph.add_node(())).collect();
+        graph.add_edge(nodes[0], nodes[1], ());
+        graph.add_edge(nodes[1], nodes[2], ());
+        graph.add_edge(nodes[2], nodes[0], ());
+
+        assert_eq!(graph.average_clustering_coefficient(), 1.0);
+    }
+
+    #[test]
+    fn test_shortest_path_metrics_path_graph() {
+        use petgraph::graph::UnGraph;
+
+        let mut graph: UnGraph<(), ()> = UnGraph::new_undirected();
+        let nodes: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+        for i in 0..(nodes.len() - 1) {
+            graph.add_edge(nodes[i], nodes[i + 1], ());
+        }
+
+        assert_eq!(graph.diameter(), 3);
+        // Reachable ordered pair distances: {1,1,2,2,3,3,1,1,2,2,1,1} over 4 nodes -> sum 20, 12 pairs
+        assert_eq!(graph.average_shortest_path_length(), 20.0 / 12.0);
+    }
 }
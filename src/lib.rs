@@ -0,0 +1,11 @@
+pub mod args;
+pub mod bitset;
+pub mod fs_utils;
+pub mod gen;
+pub mod graph_builder;
+pub mod graph_utils;
+pub mod models;
+pub mod power_law;
+pub mod simulation;
+pub mod utils;
+pub mod vertices_evolution;
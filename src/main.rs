@@ -2,13 +2,18 @@ use std::path::{Path, PathBuf};
 
 use barabasi_albert_simulation::{
     args::{Args, ArgsGraphType, ArgsModelType},
-    fs_utils::write_values_to_file,
+    fs_utils::{
+        write_f64_values_to_file, write_network_metrics_to_file, write_power_law_fit_to_file,
+        write_values_to_file,
+    },
     models::{
         BarabasiAlbertClassic, BarabasiAlbertNoGrowth, BarabasiAlbertRandomAttachement, ModelConfig,
     },
+    power_law::fit_power_law,
     simulation::Simulation,
 };
 use clap::Parser;
+use rand::{rngs::StdRng, Rng, SeedableRng};
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
@@ -19,8 +24,12 @@ fn main() -> anyhow::Result<()> {
     // For the `BarabasiAlbertNoGrowth` models, the tracked vertex will be the one picked at time i
     static TRACKED_ARRIVALS: &[usize] = &[1, 10, 100, 1000];
 
+    let seed = args.seed.unwrap_or_else(|| rand::thread_rng().gen());
+    println!("Using RNG seed: {seed}");
+    let mut rng = StdRng::seed_from_u64(seed);
+
     let model_config = ModelConfig::from_args(&args, TRACKED_ARRIVALS);
-    simulate_custom(&model_config, args.iterations, args.model)?;
+    simulate_custom(&model_config, args.iterations, args.model, &mut rng)?;
     //simulate_builtin(&model_config, args.iteration_number)?;
     Ok(())
 }
@@ -29,18 +38,18 @@ fn simulate_custom(
     model_config: &ModelConfig,
     iteration_number: usize,
     model_type: ArgsModelType,
+    rng: &mut StdRng,
 ) -> anyhow::Result<()> {
     let sim_custom = Simulation::new(iteration_number);
 
     let over = match model_type {
         ArgsModelType::GrowthPreferential => {
-            sim_custom.simulate::<BarabasiAlbertClassic>(*model_config)
-        }
-        ArgsModelType::GrowthRandom => {
-            sim_custom.simulate::<BarabasiAlbertRandomAttachement>(*model_config)
+            sim_custom.simulate_with_tracking::<BarabasiAlbertClassic, _>(*model_config, rng)
         }
+        ArgsModelType::GrowthRandom => sim_custom
+            .simulate_with_tracking::<BarabasiAlbertRandomAttachement, _>(*model_config, rng),
         ArgsModelType::NoGrowthPreferential => {
-            sim_custom.simulate::<BarabasiAlbertNoGrowth>(*model_config)
+            sim_custom.simulate_with_tracking::<BarabasiAlbertNoGrowth, _>(*model_config, rng)
         }
     };
 
@@ -71,11 +80,44 @@ fn simulate_custom(
         let vertices_evolution_path = generate_path(custom_path, "vertices_evolution", Some("txt"));
 
         if let Some(value) = arrival_evolution.get(vertex) {
-            write_values_to_file(value.clone(), vertices_evolution_path)?;
+            write_f64_values_to_file(value.clone(), vertices_evolution_path)?;
         }
     }
 
-    let degree_sequence = over.get_degree_sequence();
+    let degree_sequence = over.get_mean_degree_sequence();
+
+    if let Some(fit) = fit_power_law(&degree_sequence) {
+        let power_law_path = generate_path(
+            format!(
+                "{}_n={}_m={}_tmax={}_it={}",
+                model_name,
+                &model_config.initial_nodes,
+                &model_config.edges_increment,
+                &model_config.end_time,
+                over.iteration_number
+            ),
+            "power_law_fits",
+            Some("txt"),
+        );
+        write_power_law_fit_to_file(&fit, power_law_path)?;
+    }
+
+    if let Some(metrics) = over.get_mean_metrics() {
+        let metrics_path = generate_path(
+            format!(
+                "{}_n={}_m={}_tmax={}_it={}",
+                model_name,
+                &model_config.initial_nodes,
+                &model_config.edges_increment,
+                &model_config.end_time,
+                over.iteration_number
+            ),
+            "network_metrics",
+            Some("txt"),
+        );
+        write_network_metrics_to_file(&metrics, metrics_path)?;
+    }
+
     write_values_to_file(degree_sequence, path)?;
     Ok(())
 }
@@ -101,6 +143,29 @@ fn validate_args(args: &Args) -> anyhow::Result<()> {
         Err(anyhow::anyhow!(
             "Starting graph `Disconnected` is not allowed with models `GrowthPreferential` or `GrowthRandom`."
         ))
+    } else if args.starting_graph == ArgsGraphType::FromFile && args.starting_graph_file.is_none()
+    {
+        Err(anyhow::anyhow!(
+            "`--starting-graph-file` is required when `--starting-graph from-file` is set."
+        ))
+    } else if args.starting_graph == ArgsGraphType::Gnp && args.seed_edge_prob.is_none() {
+        Err(anyhow::anyhow!(
+            "`--seed-edge-prob` is required when `--starting-graph gnp` is set."
+        ))
+    } else if args.starting_graph == ArgsGraphType::Gnm && args.seed_edge_count.is_none() {
+        Err(anyhow::anyhow!(
+            "`--seed-edge-count` is required when `--starting-graph gnm` is set."
+        ))
+    } else if args.starting_graph == ArgsGraphType::Gnm
+        && args
+            .seed_edge_count
+            .is_some_and(|m| m > args.n * (args.n - 1) / 2)
+    {
+        Err(anyhow::anyhow!(
+            "`--seed-edge-count` exceeds the {} possible edges for `-n {}`.",
+            args.n * (args.n - 1) / 2,
+            args.n
+        ))
     } else {
         Ok(())
     }
@@ -1,23 +1,28 @@
 use core::panic;
-use std::collections::HashMap;
 
 use petgraph::graph::{NodeIndex, UnGraph};
 use petgraph_gen::{complete_graph, star_graph};
-use rand::{distributions::Uniform, prelude::Distribution, thread_rng, Rng};
+use rand::{distributions::Uniform, prelude::Distribution, Rng};
 
 use crate::{
-    args::{Args, ArgsGraphType},
+    args::{Args, ArgsGraphType, ArgsTrackMetric},
+    bitset::{PackedBitMatrix, PackedBitSet},
+    graph_builder::{gnm_graph, gnp_graph},
     graph_utils::Complete,
+    vertices_evolution::{TrackMetric, TrackVertices, VertexTracker},
 };
 
-/// A Model that is capable of itself from a `ModelConfig`
-pub trait FromModelConfig {
-    fn from_model_config(model_config: ModelConfig) -> Self;
+/// A Model that is capable of building itself from a `ModelConfig`, driven by an external
+/// `R: Rng` so the starting graph (`Gnp`/`Gnm`) is generated from the same seeded RNG as the
+/// rest of the run, rather than from an unseeded `thread_rng()`.
+pub trait FromModelConfig<R> {
+    fn from_model_config(model_config: ModelConfig, rng: &mut R) -> Self;
 }
 
-/// A Mode that is capable to generate into a graph
-pub trait Gen {
-    fn generate(&mut self) -> UnGraph<(), ()>;
+/// A Mode that is capable to generate into a graph, driven by an external `R: Rng` so runs
+/// can be made deterministic by the caller.
+pub trait Gen<R> {
+    fn generate(&mut self, rng: &mut R) -> UnGraph<(), ()>;
 }
 
 /// A Model that is capable of stepping into the simulation
@@ -25,21 +30,45 @@ pub trait Step<R> {
     fn step(&mut self, rng: &mut R) -> bool;
 }
 
-/// A Model that is able to track the evolution of a vertex into the simulation
-pub trait TrackVertices {
-    fn get_vertex_evolution(&self, vertex_id: NodeIndex) -> Vec<usize>;
-    fn update_vertices_evolution(&mut self, time: usize);
-}
-
 #[derive(Debug, Copy, Clone)]
+// Only `Serialize` is derived below, never `Deserialize`: `FromFile` borrows a `&'static str`
+// leaked at startup, and a deserializer has no `'static` buffer to borrow into.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum GraphType {
     Complete,
     Star,
     Disconnected,
+    /// A starting graph loaded from an adjacency-matrix or edge-list file at the given path.
+    FromFile(&'static str),
+    /// Erdős–Rényi G(n, p), with the given edge inclusion probability.
+    Gnp(f64),
+    /// Erdős–Rényi G(n, m), with the given exact edge count.
+    Gnm(usize),
+}
+
+/// Build a starting graph of `initial_nodes` vertices for every `GraphType` that does not need
+/// special handling from the calling model (`Disconnected` is only meaningful to the no-growth
+/// model, which builds it itself).
+fn build_starting_graph<R: Rng>(
+    graph_type: GraphType,
+    initial_nodes: usize,
+    rng: &mut R,
+) -> UnGraph<(), ()> {
+    match graph_type {
+        GraphType::Complete => complete_graph(initial_nodes),
+        GraphType::Star => star_graph(initial_nodes - 1),
+        GraphType::Disconnected => UnGraph::new_undirected(),
+        GraphType::FromFile(path) => crate::fs_utils::read_graph_from_file(path, initial_nodes)
+            .unwrap_or_else(|err| panic!("Failed to load starting graph from {path}: {err}")),
+        GraphType::Gnp(p) => gnp_graph(initial_nodes, p, rng),
+        GraphType::Gnm(m) => gnm_graph(initial_nodes, m, rng),
+    }
 }
 
 /// Represent the starting parameters of a Barabasi-Albert model.
+// Only `Serialize` is derived below, for the same `&'static [usize]` reason as `GraphType`.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ModelConfig {
     pub initial_nodes: usize,
     /// Number of new edges per time step of the simulation
@@ -50,49 +79,59 @@ pub struct ModelConfig {
     // Times `t` at which we start tracking a vertex evolution,
     // The vertex is either the one added at time `t` for `BarabasiAlbertClassic` and `BarabasiAlbertRandomAttachement`,
     // or the node connected at time `t` for `BarabasiAlbertNoGrowth`.
-    pub tracked_timesteps: &'static [usize],
+    pub tracked_arrivals: &'static [usize],
+    /// Metric recorded over time for the vertices listed in `tracked_arrivals`
+    pub track_metric: TrackMetric,
+    /// Whether to compute the (expensive, all-pairs-shortest-path) `NetworkMetrics` for each
+    /// Monte Carlo iteration.
+    pub compute_network_metrics: bool,
 }
 
 /// A Barabasi-Albert model with vertex growth and preferential attachement
 pub struct BarabasiAlbertClassic {
     pub model_config: ModelConfig,
     graph: UnGraph<(), ()>,
+    // Each node appears once per incident edge endpoint, so its multiplicity equals its current
+    // degree: sampling uniformly from this multiset is exactly proportional to degree, giving
+    // preferential attachment in O(1) per draw instead of scanning degrees.
     stubs: Vec<NodeIndex>,
-    picked: Vec<bool>,
+    picked: PackedBitSet,
     targets: Vec<NodeIndex>,
-    // TODO refactor pour prendre une strcture commune qui isole ce comportement
-    vertices_evolution: HashMap<NodeIndex, Vec<usize>>,
+    vertex_tracker: VertexTracker,
 }
 
 /// A Barabasi-Albert model with vertex growth and random attachement
 pub struct BarabasiAlbertRandomAttachement {
     pub model_config: ModelConfig,
     graph: UnGraph<(), ()>,
-    // To avoid calling `graph.node_indices().count()` which is O(n)
+    // To avoid calling `graph.node_indices().count()` which is O(n). Node indices are assigned
+    // densely from 0, so sampling uniformly over `0..node_count` is equivalent to sampling the
+    // distinct node list uniformly, with no preferential weighting.
     node_count: usize,
-    picked: Vec<bool>,
+    picked: PackedBitSet,
     targets: Vec<NodeIndex>,
-    // TODO refactor pour prendre une strcture commune qui isole ce comportement
-    // Refactor cela pour que cela soit le strcture qui implémente le trait et que je n'ai aps
-    // besoin de implementé en obucle pour tous les trusc
-    vertices_evolution: HashMap<NodeIndex, Vec<usize>>,
+    vertex_tracker: VertexTracker,
 }
 
 /// A Barabasi-Albert model with preferential attachement but without vertex growth.
 pub struct BarabasiAlbertNoGrowth {
     model_config: ModelConfig,
     graph: UnGraph<(), ()>,
+    // Same multiset-of-endpoints technique as `BarabasiAlbertClassic::stubs`: multiplicity
+    // equals degree, so uniform sampling over it is proportional to degree.
     stubs: Vec<NodeIndex>,
-    picked: Vec<bool>,
+    picked: PackedBitSet,
     targets: Vec<NodeIndex>,
-    tracked_vertices: Vec<NodeIndex>,
-    vertices_evolution: HashMap<NodeIndex, Vec<usize>>,
+    vertex_tracker: VertexTracker,
     initial_uniform: Uniform<usize>,
     current_time_step: usize,
+    // Packed adjacency matrix used to test `find_edge` in O(1) instead of scanning
+    // the node's incident edges, which dominates as `edges_increment` approaches `initial_nodes`.
+    adjacency: PackedBitMatrix,
 }
 
 impl ModelConfig {
-    pub fn from_args(args: &Args, tracked_timesteps: &'static [usize]) -> Self {
+    pub fn from_args(args: &Args, tracked_arrivals: &'static [usize]) -> Self {
         assert!(
             args.n >= 1,
             "The number of initial vertices must be greater than 1"
@@ -116,21 +155,45 @@ impl ModelConfig {
                 ArgsGraphType::Complete => GraphType::Complete,
                 ArgsGraphType::Star => GraphType::Star,
                 ArgsGraphType::Disconnected => GraphType::Disconnected,
+                ArgsGraphType::FromFile => {
+                    let path = args.starting_graph_file.as_ref().expect(
+                        "`--starting-graph-file` is required when `--starting-graph from-file` is set",
+                    );
+                    GraphType::FromFile(Box::leak(
+                        path.to_string_lossy().into_owned().into_boxed_str(),
+                    ))
+                }
+                ArgsGraphType::Gnp => {
+                    let p = args.seed_edge_prob.expect(
+                        "`--seed-edge-prob` is required when `--starting-graph gnp` is set",
+                    );
+                    GraphType::Gnp(p)
+                }
+                ArgsGraphType::Gnm => {
+                    let m = args.seed_edge_count.expect(
+                        "`--seed-edge-count` is required when `--starting-graph gnm` is set",
+                    );
+                    GraphType::Gnm(m)
+                }
+            },
+            tracked_arrivals,
+            track_metric: match args.track_metric {
+                ArgsTrackMetric::Degree => TrackMetric::Degree,
+                ArgsTrackMetric::ClusteringCoefficient => TrackMetric::ClusteringCoefficient,
+                ArgsTrackMetric::ComponentSize => TrackMetric::ComponentSize,
             },
-            tracked_timesteps,
+            compute_network_metrics: args.network_metrics,
         }
     }
 }
 
-impl FromModelConfig for BarabasiAlbertClassic {
-    fn from_model_config(model_config: ModelConfig) -> Self {
-        let graph: UnGraph<(), ()> = match model_config.starting_graph_type {
-            GraphType::Complete => complete_graph(model_config.initial_nodes),
-            GraphType::Star => star_graph(model_config.initial_nodes - 1),
-            GraphType::Disconnected => {
-                panic!("This initial graph type is only for barabasi-abert no growth")
-            }
-        };
+impl<R: Rng> FromModelConfig<R> for BarabasiAlbertClassic {
+    fn from_model_config(model_config: ModelConfig, rng: &mut R) -> Self {
+        if let GraphType::Disconnected = model_config.starting_graph_type {
+            panic!("This initial graph type is only for barabasi-abert no growth");
+        }
+        let graph: UnGraph<(), ()> =
+            build_starting_graph(model_config.starting_graph_type, model_config.initial_nodes, rng);
 
         let mut stubs = vec![];
         for node in graph.node_indices() {
@@ -139,51 +202,56 @@ impl FromModelConfig for BarabasiAlbertClassic {
             }
         }
 
-        let picked = vec![false; model_config.initial_nodes + model_config.end_time];
+        let picked = PackedBitSet::new(model_config.initial_nodes + model_config.end_time);
         let targets = vec![NodeIndex::new(0); model_config.edges_increment];
 
+        let mut vertex_tracker = VertexTracker::new(model_config.track_metric);
+        for &t in model_config.tracked_arrivals {
+            vertex_tracker.track_vertex(t, NodeIndex::new(model_config.initial_nodes + t - 1));
+        }
+
         Self {
             model_config,
             graph,
             stubs,
             picked,
             targets,
-            vertices_evolution: HashMap::new(),
+            vertex_tracker,
         }
     }
 }
 
-impl FromModelConfig for BarabasiAlbertRandomAttachement {
-    fn from_model_config(model_config: ModelConfig) -> Self {
-        let graph: UnGraph<(), ()> = match model_config.starting_graph_type {
-            GraphType::Complete => complete_graph(model_config.initial_nodes),
-            GraphType::Star => star_graph(model_config.initial_nodes - 1),
-            GraphType::Disconnected => {
-                panic!("This initial graph type is only for barabasi-abert no growth")
-            }
-        };
+impl<R: Rng> FromModelConfig<R> for BarabasiAlbertRandomAttachement {
+    fn from_model_config(model_config: ModelConfig, rng: &mut R) -> Self {
+        if let GraphType::Disconnected = model_config.starting_graph_type {
+            panic!("This initial graph type is only for barabasi-abert no growth");
+        }
+        let graph: UnGraph<(), ()> =
+            build_starting_graph(model_config.starting_graph_type, model_config.initial_nodes, rng);
 
-        let picked = vec![false; model_config.initial_nodes + model_config.end_time];
+        let picked = PackedBitSet::new(model_config.initial_nodes + model_config.end_time);
         let targets = vec![NodeIndex::new(0); model_config.edges_increment];
 
+        let mut vertex_tracker = VertexTracker::new(model_config.track_metric);
+        for &t in model_config.tracked_arrivals {
+            vertex_tracker.track_vertex(t, NodeIndex::new(model_config.initial_nodes + t - 1));
+        }
+
         Self {
             model_config,
             graph,
             picked,
             targets,
-            vertices_evolution: HashMap::new(),
+            vertex_tracker,
             node_count: model_config.initial_nodes,
         }
     }
 }
 
-impl FromModelConfig for BarabasiAlbertNoGrowth {
-    fn from_model_config(model_config: ModelConfig) -> Self {
-        let mut graph: UnGraph<(), ()> = match model_config.starting_graph_type {
-            GraphType::Complete => complete_graph(model_config.initial_nodes),
-            GraphType::Star => star_graph(model_config.initial_nodes - 1),
-            GraphType::Disconnected => UnGraph::<(), ()>::new_undirected(),
-        };
+impl<R: Rng> FromModelConfig<R> for BarabasiAlbertNoGrowth {
+    fn from_model_config(model_config: ModelConfig, rng: &mut R) -> Self {
+        let mut graph: UnGraph<(), ()> =
+            build_starting_graph(model_config.starting_graph_type, model_config.initial_nodes, rng);
 
         let mut stubs = vec![];
 
@@ -201,19 +269,30 @@ impl FromModelConfig for BarabasiAlbertNoGrowth {
             }
         }
 
-        let picked = vec![false; model_config.initial_nodes + model_config.end_time];
+        let picked = PackedBitSet::new(model_config.initial_nodes + model_config.end_time);
         let targets = vec![NodeIndex::new(0); model_config.edges_increment];
 
+        // This model never grows past `initial_nodes` (no growth: `step` only rewires existing
+        // vertices), so the adjacency matrix only ever needs to address `0..initial_nodes`,
+        // unlike `picked` which is sized for the growth models' `initial_nodes + end_time`.
+        let mut adjacency =
+            PackedBitMatrix::new(model_config.initial_nodes, model_config.initial_nodes);
+        for edge in graph.edge_indices() {
+            let (source, target) = graph.edge_endpoints(edge).unwrap();
+            adjacency.set(source.index(), target.index());
+            adjacency.set(target.index(), source.index());
+        }
+
         Self {
             model_config,
             graph,
             stubs,
             picked,
             targets,
-            vertices_evolution: HashMap::new(),
+            vertex_tracker: VertexTracker::new(model_config.track_metric),
             initial_uniform: Uniform::new(0, model_config.initial_nodes),
-            tracked_vertices: vec![],
             current_time_step: 0,
+            adjacency,
         }
     }
 }
@@ -230,8 +309,8 @@ where
             let random_index = uniform.sample(rng);
             let target = self.stubs[random_index];
             // To prevent multi-edge
-            if !self.picked[target.index()] {
-                self.picked[target.index()] = true;
+            if !self.picked.contains(target.index()) {
+                self.picked.set(target.index());
                 self.targets[i] = target;
                 i += 1;
             }
@@ -241,7 +320,7 @@ where
             self.graph.add_edge(new_node, target, ());
             self.stubs.push(new_node);
             self.stubs.push(target);
-            self.picked[target.index()] = false;
+            self.picked.clear(target.index());
         }
 
         true
@@ -259,8 +338,8 @@ where
         while i < self.model_config.edges_increment {
             let random_index = uniform.sample(rng);
             // To prevent multi-edge
-            if !self.picked[random_index] {
-                self.picked[random_index] = true;
+            if !self.picked.contains(random_index) {
+                self.picked.set(random_index);
                 self.targets[i] = NodeIndex::new(random_index);
                 i += 1;
             }
@@ -268,7 +347,7 @@ where
 
         for &target in &self.targets {
             self.graph.add_edge(new_node, target, ());
-            self.picked[target.index()] = false;
+            self.picked.clear(target.index());
         }
 
         self.node_count += 1;
@@ -290,10 +369,11 @@ where
         // Add the node that have been picked at time step i to the list of tracked vertex
         if self
             .model_config
-            .tracked_timesteps
+            .tracked_arrivals
             .contains(&self.current_time_step)
         {
-            self.tracked_vertices.push(random_node);
+            self.vertex_tracker
+                .track_vertex(self.current_time_step, random_node);
         }
         let mut i = 0;
         while i < self.model_config.edges_increment {
@@ -301,10 +381,10 @@ where
             let target = self.stubs[random_index];
             // To prevent multi-edge
             if target != random_node
-                && !self.picked[target.index()]
-                && self.graph.find_edge(random_node, target).is_none()
+                && !self.picked.contains(target.index())
+                && !self.adjacency.contains(random_node.index(), target.index())
             {
-                self.picked[target.index()] = true;
+                self.picked.set(target.index());
                 self.targets[i] = target;
                 i += 1;
             }
@@ -314,129 +394,79 @@ where
             self.graph.add_edge(random_node, target, ());
             self.stubs.push(target);
             self.stubs.push(random_node);
-            self.picked[target.index()] = false;
+            self.picked.clear(target.index());
+            self.adjacency.set(random_node.index(), target.index());
+            self.adjacency.set(target.index(), random_node.index());
         }
 
+        self.current_time_step += 1;
         true
     }
 }
 
-// TODO une fois que j'ai fait toutes les implementations et que tout marche
-// Regarder si je ne peux pas foutre tout cela dans une blanket implementation
-impl Gen for BarabasiAlbertClassic {
-    fn generate(&mut self) -> UnGraph<(), ()> {
-        let mut rng = thread_rng();
+impl<R: Rng> Gen<R> for BarabasiAlbertClassic {
+    fn generate(&mut self, rng: &mut R) -> UnGraph<(), ()> {
         for time in 1..=self.model_config.end_time {
-            if !self.step(&mut rng) {
+            if !self.step(rng) {
                 break;
             }
-            self.update_vertices_evolution(time);
+            self.vertex_tracker.update(&self.graph, time);
         }
         self.graph.clone()
     }
 }
 
-impl Gen for BarabasiAlbertRandomAttachement {
-    fn generate(&mut self) -> UnGraph<(), ()> {
-        let mut rng = thread_rng();
+impl<R: Rng> Gen<R> for BarabasiAlbertRandomAttachement {
+    fn generate(&mut self, rng: &mut R) -> UnGraph<(), ()> {
         for time in 1..=self.model_config.end_time {
-            if !self.step(&mut rng) {
+            if !self.step(rng) {
                 break;
             }
-            self.update_vertices_evolution(time);
+            self.vertex_tracker.update(&self.graph, time);
         }
         self.graph.clone()
     }
 }
 
-impl Gen for BarabasiAlbertNoGrowth {
-    fn generate(&mut self) -> UnGraph<(), ()> {
-        let mut rng = thread_rng();
+impl<R: Rng> Gen<R> for BarabasiAlbertNoGrowth {
+    fn generate(&mut self, rng: &mut R) -> UnGraph<(), ()> {
         for time in 1..=self.model_config.end_time {
-            if !self.step(&mut rng) {
+            if !self.step(rng) {
                 break;
             }
-            self.update_vertices_evolution(time);
+            self.vertex_tracker.update(&self.graph, time);
         }
         self.graph.clone()
     }
 }
 
-// TODO une fois que j'ai fait toutes les implementations et que tout marche
-// Regarder si je ne peux pas foutre tout cela dans une blanket implementation
-// REGARDER AUSSI si je peux pas wrapper la logique de suivre les tracked vertex dans une structure
-// de donnée custom
 impl TrackVertices for BarabasiAlbertClassic {
-    fn get_vertex_evolution(&self, vertex_id: NodeIndex) -> Vec<usize> {
-        let default = Vec::new();
-        self.vertices_evolution
-            .get(&vertex_id)
-            .unwrap_or(&default)
-            .clone()
+    fn get_arrival_evolution(&self, arrival_time: &usize) -> Option<Vec<f64>> {
+        self.vertex_tracker.get_arrival_evolution(arrival_time)
     }
 
-    fn update_vertices_evolution(&mut self, time: usize) {
-        for vertex in self.model_config.tracked_timesteps {
-            let node_index = NodeIndex::new(*vertex);
-            // Only start updating the node degree evolution if we are at least at time step where
-            // he arrive
-            if *vertex > time {
-                continue;
-            }
-            self.vertices_evolution
-                .entry(node_index)
-                .or_default()
-                .push(self.graph.neighbors(node_index).count())
-        }
+    fn update(&mut self, graph: &UnGraph<(), ()>, time: usize) {
+        self.vertex_tracker.update(graph, time);
     }
 }
 
 impl TrackVertices for BarabasiAlbertRandomAttachement {
-    fn get_vertex_evolution(&self, vertex_id: NodeIndex) -> Vec<usize> {
-        let default = Vec::new();
-        self.vertices_evolution
-            .get(&vertex_id)
-            .unwrap_or(&default)
-            .clone()
+    fn get_arrival_evolution(&self, arrival_time: &usize) -> Option<Vec<f64>> {
+        self.vertex_tracker.get_arrival_evolution(arrival_time)
     }
 
-    fn update_vertices_evolution(&mut self, time: usize) {
-        for vertex in self.model_config.tracked_timesteps {
-            let node_index = NodeIndex::new(*vertex);
-            // Only start updating the node degree evolution if we are at least at time step where
-            // he arrive
-            if *vertex > time {
-                continue;
-            }
-            self.vertices_evolution
-                .entry(node_index)
-                .or_default()
-                .push(self.graph.neighbors(node_index).count())
-        }
+    fn update(&mut self, graph: &UnGraph<(), ()>, time: usize) {
+        self.vertex_tracker.update(graph, time);
     }
 }
 
 impl TrackVertices for BarabasiAlbertNoGrowth {
-    // TODO mettre une fonction get vertex evolution all pour pouvoir tout gather
-    fn get_vertex_evolution(&self, vertex_id: NodeIndex) -> Vec<usize> {
-        let default = Vec::new();
-        self.vertices_evolution
-            .get(&vertex_id)
-            .unwrap_or(&default)
-            .clone()
+    fn get_arrival_evolution(&self, arrival_time: &usize) -> Option<Vec<f64>> {
+        self.vertex_tracker.get_arrival_evolution(arrival_time)
     }
 
-    /// This implementation is different because this time, we don't track the node that we add at
-    /// time i but rather the node that has been choosen to be connected at time `time`, which can
-    /// or not be the `time` vertex.
-    fn update_vertices_evolution(&mut self, _: usize) {
-        for vertex in &self.tracked_vertices {
-            let node_index = vertex;
-            self.vertices_evolution
-                .entry(*node_index)
-                .or_default()
-                .push(self.graph.neighbors(*node_index).count())
-        }
+    fn update(&mut self, graph: &UnGraph<(), ()>, time: usize) {
+        self.vertex_tracker.update(graph, time);
     }
 }
 
@@ -444,20 +474,29 @@ impl TrackVertices for BarabasiAlbertNoGrowth {
 mod test {
     use petgraph::visit::EdgeRef;
 
-    use crate::models::{BarabasiAlbertClassic, FromModelConfig, Gen, GraphType, ModelConfig};
+    use rand::thread_rng;
+
+    use crate::{
+        graph_utils::DegreeSequence,
+        models::{BarabasiAlbertClassic, FromModelConfig, Gen, GraphType, ModelConfig},
+        vertices_evolution::TrackMetric,
+    };
 
     const CONFIG: ModelConfig = ModelConfig {
         initial_nodes: 5,
         edges_increment: 3,
         end_time: 10,
         starting_graph_type: GraphType::Complete,
-        tracked_timesteps: &[],
+        tracked_arrivals: &[],
+        track_metric: TrackMetric::Degree,
+        compute_network_metrics: false,
     };
 
     #[test]
     fn test_barabasi_classic_node_count() {
-        let mut model: BarabasiAlbertClassic = FromModelConfig::from_model_config(CONFIG);
-        let graph = model.generate();
+        let mut rng = thread_rng();
+        let mut model: BarabasiAlbertClassic = FromModelConfig::from_model_config(CONFIG, &mut rng);
+        let graph = model.generate(&mut rng);
 
         // Total nodes = Initial nodes + nodes added at each time step
         assert_eq!(
@@ -468,8 +507,9 @@ mod test {
 
     #[test]
     fn test_barabasi_classic_edge_count() {
-        let mut model: BarabasiAlbertClassic = FromModelConfig::from_model_config(CONFIG);
-        let graph = model.generate();
+        let mut rng = thread_rng();
+        let mut model: BarabasiAlbertClassic = FromModelConfig::from_model_config(CONFIG, &mut rng);
+        let graph = model.generate(&mut rng);
 
         // Initial edges = (n * (n - 1)) / 2 for a fully connected graph
         let initial_edges = (CONFIG.initial_nodes * (CONFIG.initial_nodes - 1)) / 2;
@@ -480,8 +520,9 @@ mod test {
 
     #[test]
     fn test_barabasi_classic_no_multi_edges() {
-        let mut model: BarabasiAlbertClassic = FromModelConfig::from_model_config(CONFIG);
-        let graph = model.generate();
+        let mut rng = thread_rng();
+        let mut model: BarabasiAlbertClassic = FromModelConfig::from_model_config(CONFIG, &mut rng);
+        let graph = model.generate(&mut rng);
 
         for node in graph.node_indices() {
             let mut neighbors = vec![];
@@ -495,10 +536,39 @@ mod test {
 
     #[test]
     fn test_barabasi_classic_graph_connectivity() {
-        let mut model: BarabasiAlbertClassic = FromModelConfig::from_model_config(CONFIG);
-        let graph = model.generate();
+        let mut rng = thread_rng();
+        let mut model: BarabasiAlbertClassic = FromModelConfig::from_model_config(CONFIG, &mut rng);
+        let graph = model.generate(&mut rng);
 
         let connected_components = petgraph::algo::connected_components(&graph);
         assert_eq!(connected_components, 1, "Graph is not connected");
     }
+
+    #[test]
+    fn test_gnp_starting_graph_is_reproducible_with_same_seed() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let config = ModelConfig {
+            starting_graph_type: GraphType::Gnp(0.5),
+            ..CONFIG
+        };
+
+        let mut rng_a = StdRng::seed_from_u64(42);
+        let mut model_a: BarabasiAlbertClassic =
+            FromModelConfig::from_model_config(config, &mut rng_a);
+        let graph_a = model_a.generate(&mut rng_a);
+
+        let mut rng_b = StdRng::seed_from_u64(42);
+        let mut model_b: BarabasiAlbertClassic =
+            FromModelConfig::from_model_config(config, &mut rng_b);
+        let graph_b = model_b.generate(&mut rng_b);
+
+        assert_eq!(graph_a.node_count(), graph_b.node_count());
+        assert_eq!(graph_a.edge_count(), graph_b.edge_count());
+        assert_eq!(
+            graph_a.degree_sequence(),
+            graph_b.degree_sequence(),
+            "same seed must reproduce the same starting graph and the same simulation run"
+        );
+    }
 }
@@ -0,0 +1,131 @@
+/// Parameters of a discrete power-law tail `P(k) ~ k^-alpha` fitted on `k >= k_min`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PowerLawFit {
+    pub k_min: usize,
+    pub alpha: f64,
+    pub standard_error: f64,
+    pub ks_distance: f64,
+}
+
+/// Fit the discrete maximum-likelihood power-law exponent on `degrees`.
+///
+/// Candidate lower cutoffs are swept over every distinct nonzero value present in `degrees`, and the
+/// cutoff minimizing the Kolmogorov-Smirnov distance between the empirical and fitted survival
+/// functions is kept. Returns `None` if no candidate cutoff leaves enough data to fit.
+pub fn fit_power_law(degrees: &[usize]) -> Option<PowerLawFit> {
+    let mut candidates: Vec<usize> = degrees.iter().copied().filter(|&k| k >= 1).collect();
+    candidates.sort_unstable();
+    candidates.dedup();
+
+    candidates
+        .into_iter()
+        .filter_map(|k_min| fit_at(degrees, k_min))
+        .min_by(|a, b| a.ks_distance.total_cmp(&b.ks_distance))
+}
+
+fn fit_at(degrees: &[usize], k_min: usize) -> Option<PowerLawFit> {
+    let tail: Vec<f64> = degrees
+        .iter()
+        .copied()
+        .filter(|&k| k >= k_min)
+        .map(|k| k as f64)
+        .collect();
+
+    let n = tail.len();
+    if n < 2 {
+        return None;
+    }
+
+    let k_min_f = k_min as f64;
+    let sum_ln: f64 = tail.iter().map(|&k| (k / (k_min_f - 0.5)).ln()).sum();
+    if sum_ln <= 0.0 {
+        return None;
+    }
+
+    let alpha = 1.0 + n as f64 / sum_ln;
+    let standard_error = (alpha - 1.0) / (n as f64).sqrt();
+    let ks_distance = kolmogorov_smirnov_distance(&tail, k_min, alpha);
+
+    Some(PowerLawFit {
+        k_min,
+        alpha,
+        standard_error,
+        ks_distance,
+    })
+}
+
+/// Maximum absolute gap between the empirical survival function `S(k) = P(X >= k)` of `tail`
+/// and the survival function of the fitted discrete power law, over every value in `tail`.
+fn kolmogorov_smirnov_distance(tail: &[f64], k_min: usize, alpha: f64) -> f64 {
+    let n = tail.len() as f64;
+    let mut sorted = tail.to_vec();
+    sorted.sort_by(f64::total_cmp);
+
+    let normalization = hurwitz_zeta(alpha, k_min as f64);
+
+    let mut max_distance = 0.0_f64;
+    let mut cumulative = 0.0_f64;
+    let mut i = 0;
+    while i < sorted.len() {
+        let value = sorted[i];
+        let mut run = 0.0;
+        while i < sorted.len() && sorted[i] == value {
+            run += 1.0;
+            i += 1;
+        }
+
+        let empirical_survival = (n - cumulative) / n;
+        let fitted_survival = hurwitz_zeta(alpha, value) / normalization;
+        max_distance = max_distance.max((empirical_survival - fitted_survival).abs());
+
+        cumulative += run;
+    }
+    max_distance
+}
+
+/// Numerically approximate the Hurwitz zeta function `sum_{n=0}^inf 1/(n+q)^s`, summing the
+/// first terms directly and approximating the remainder with its integral tail.
+fn hurwitz_zeta(s: f64, q: f64) -> f64 {
+    const DIRECT_TERMS: u32 = 10_000;
+
+    let mut sum = 0.0;
+    for n in 0..DIRECT_TERMS {
+        sum += (n as f64 + q).powf(-s);
+    }
+
+    let tail_start = q + DIRECT_TERMS as f64;
+    sum + tail_start.powf(1.0 - s) / (s - 1.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::fit_power_law;
+
+    #[test]
+    fn test_fit_power_law_recovers_known_exponent() {
+        // Heavy-tailed sample whose counts roughly halve at each degree step (20, 8, 4, 2, 1, 1).
+        // At k_min = 1 the estimate is only about 1.9; the KS sweep settles on a higher cutoff,
+        // where alpha comes out near 3.
+        let degrees: Vec<usize> = vec![
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 3,
+            3, 3, 3, 4, 4, 5, 6,
+        ];
+        let fit = fit_power_law(&degrees).expect("fit should succeed on well-formed data");
+        assert!(
+            (2.0..4.5).contains(&fit.alpha),
+            "alpha {} should be close to 3",
+            fit.alpha
+        );
+        assert!(fit.standard_error > 0.0);
+    }
+
+    #[test]
+    fn test_fit_power_law_empty_input() {
+        assert!(fit_power_law(&[]).is_none());
+    }
+
+    #[test]
+    fn test_fit_power_law_all_zero_degrees() {
+        assert!(fit_power_law(&[0, 0, 0]).is_none());
+    }
+}
@@ -1,27 +1,89 @@
 use std::collections::HashMap;
 
-use petgraph::graph::NodeIndex;
+use petgraph::graph::UnGraph;
+use rand::{Rng, SeedableRng};
+use rayon::prelude::*;
 
 use crate::{
-    graph_utils::DegreeSequence,
-    models::{FromModelConfig, Gen, ModelConfig, TrackVertices},
+    graph_utils::{DegreeSequence, NetworkMetrics},
+    models::{FromModelConfig, Gen, ModelConfig},
+    power_law::fit_power_law,
+    vertices_evolution::TrackVertices,
 };
 
 /// Barabasi-Albert model is random by nature, to have analysis on the models
 /// we simulate the results `iteration_number` time with the goal to average our two simulation goal
 /// 1. The degree sequence of the network
-/// 2. The evolution of degree of the different vertices listed inside `tracked_vertices`, those
-///    degree are identified by the time step `i` they arrive in the network.
+/// 2. The evolution of the tracked metric for the vertices listed inside `tracked_arrivals`, those
+///    are identified by the time step `i` they arrive in the network.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Simulation<S: SimulationState> {
     pub iteration_number: usize,
     pub degree_sequence: Option<Vec<usize>>,
+    #[cfg_attr(feature = "serde", serde(flatten))]
     state: S,
 }
 
 pub trait SimulationState {}
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Over {
-    pub vertices_evolution: Option<HashMap<NodeIndex, Vec<usize>>>,
+    pub vertices_evolution: Option<HashMap<usize, Vec<f64>>>,
+    network_metrics: Option<MeanNetworkMetrics>,
+    degree_distribution: Option<Vec<f64>>,
+}
+
+/// Network-structure metrics averaged over every Monte Carlo iteration, to quantitatively
+/// confirm Barabasi-Albert graphs exhibit short paths with low clustering.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MeanNetworkMetrics {
+    pub average_clustering_coefficient: f64,
+    pub connected_components_count: f64,
+    pub average_shortest_path_length: f64,
+    pub diameter: f64,
+}
+
+/// Running sum of a single iteration's network metrics, folded over the Monte Carlo loop and
+/// divided by the iteration count once the simulation is over.
+struct NetworkMetricsAccumulator {
+    average_clustering_coefficient: f64,
+    connected_components_count: f64,
+    average_shortest_path_length: f64,
+    diameter: f64,
+}
+
+impl NetworkMetricsAccumulator {
+    fn from_graph<N, E>(graph: &UnGraph<N, E>) -> Self {
+        Self {
+            average_clustering_coefficient: graph.average_clustering_coefficient(),
+            connected_components_count: graph.connected_components_count() as f64,
+            average_shortest_path_length: graph.average_shortest_path_length(),
+            diameter: graph.diameter() as f64,
+        }
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            average_clustering_coefficient: self.average_clustering_coefficient
+                + other.average_clustering_coefficient,
+            connected_components_count: self.connected_components_count
+                + other.connected_components_count,
+            average_shortest_path_length: self.average_shortest_path_length
+                + other.average_shortest_path_length,
+            diameter: self.diameter + other.diameter,
+        }
+    }
+
+    fn into_mean(self, iteration_number: usize) -> MeanNetworkMetrics {
+        let n = iteration_number as f64;
+        MeanNetworkMetrics {
+            average_clustering_coefficient: self.average_clustering_coefficient / n,
+            connected_components_count: self.connected_components_count / n,
+            average_shortest_path_length: self.average_shortest_path_length / n,
+            diameter: self.diameter / n,
+        }
+    }
 }
 
 pub struct Start {}
@@ -29,8 +91,43 @@ pub struct Start {}
 impl SimulationState for Over {}
 impl SimulationState for Start {}
 
+/// Fraction of nodes holding each degree `0..=max(sequence)`, so iterations can be averaged
+/// together even though the highest degree reached varies from run to run.
+fn degree_histogram(sequence: &[usize]) -> Vec<f64> {
+    let max_degree = sequence.iter().copied().max().unwrap_or(0);
+    let mut histogram = vec![0.0; max_degree + 1];
+    let node_count = sequence.len() as f64;
+    for &degree in sequence {
+        histogram[degree] += 1.0 / node_count;
+    }
+    histogram
+}
+
+/// Fold `histogram` into `acc`, growing `acc` if this iteration reached a higher degree than
+/// any iteration seen so far.
+fn accumulate_histogram(acc: &mut Vec<f64>, histogram: &[f64]) {
+    if histogram.len() > acc.len() {
+        acc.resize(histogram.len(), 0.0);
+    }
+    for (degree, &fraction) in histogram.iter().enumerate() {
+        acc[degree] += fraction;
+    }
+}
+
+/// Expand a degree distribution back into a degree sequence of about `node_count` entries, so
+/// `get_mean_degree_sequence` keeps returning the same shape of data as before. Each degree's
+/// count is rounded on its own, so the total can differ from `node_count` by rounding.
+fn reconstruct_degree_sequence(distribution: &[f64], node_count: usize) -> Vec<usize> {
+    let mut sequence = Vec::with_capacity(node_count);
+    for (degree, &fraction) in distribution.iter().enumerate() {
+        let count = (fraction * node_count as f64).round() as usize;
+        sequence.extend(std::iter::repeat(degree).take(count));
+    }
+    sequence
+}
+
 impl<S: SimulationState> Simulation<S> {
-    pub fn mean_vectors(vectors: &[Vec<usize>]) -> Vec<usize> {
+    pub fn mean_vectors(vectors: &[Vec<f64>]) -> Vec<f64> {
         assert!(!vectors.is_empty(), "Input vector list cannot be empty");
 
         let num_vectors = vectors.len();
@@ -43,8 +140,8 @@ impl<S: SimulationState> Simulation<S> {
 
         (0..vector_length)
             .map(|i| {
-                let sum: usize = vectors.iter().map(|v| v[i]).sum();
-                (sum as f64 / num_vectors as f64).ceil() as usize
+                let sum: f64 = vectors.iter().map(|v| v[i]).sum();
+                sum / num_vectors as f64
             })
             .collect()
     }
@@ -59,62 +156,175 @@ impl Simulation<Start> {
         }
     }
 
-    pub fn simulate<G: FromModelConfig + Gen>(self, model_config: ModelConfig) -> Simulation<Over> {
-        let mut sequence = None;
+    pub fn simulate<G: FromModelConfig<R> + Gen<R>, R: Rng>(
+        self,
+        model_config: ModelConfig,
+        rng: &mut R,
+    ) -> Simulation<Over> {
+        let mut histogram_sum: Vec<f64> = vec![];
+        let mut node_count = 0;
+        let mut metrics: Option<NetworkMetricsAccumulator> = None;
         for _ in 0..self.iteration_number {
-            let mut model: G = FromModelConfig::from_model_config(model_config);
-            let graph = model.generate();
-            if sequence.is_none() {
-                sequence = Some(graph.degree_sequence());
+            let mut model: G = FromModelConfig::from_model_config(model_config, rng);
+            let graph = model.generate(rng);
+            if model_config.compute_network_metrics {
+                metrics = Some(match metrics {
+                    Some(acc) => acc.add(NetworkMetricsAccumulator::from_graph(&graph)),
+                    None => NetworkMetricsAccumulator::from_graph(&graph),
+                });
             }
+            let sequence = graph.degree_sequence();
+            node_count = sequence.len();
+            accumulate_histogram(&mut histogram_sum, &degree_histogram(&sequence));
         }
+        let iteration_number = self.iteration_number as f64;
+        let degree_distribution: Vec<f64> = histogram_sum
+            .into_iter()
+            .map(|sum| sum / iteration_number)
+            .collect();
+        let mean_degree_sequence = reconstruct_degree_sequence(&degree_distribution, node_count);
+
         Simulation {
-            degree_sequence: sequence,
+            degree_sequence: Some(mean_degree_sequence),
             iteration_number: self.iteration_number,
             state: Over {
                 vertices_evolution: None,
+                network_metrics: metrics.map(|acc| acc.into_mean(self.iteration_number)),
+                degree_distribution: Some(degree_distribution),
             },
         }
     }
 
-    pub fn simulate_with_tracking<G: FromModelConfig + Gen + TrackVertices>(
+    pub fn simulate_with_tracking<G: FromModelConfig<R> + Gen<R> + TrackVertices, R: Rng>(
         self,
         model_config: ModelConfig,
+        rng: &mut R,
     ) -> Simulation<Over> {
-        let mut sequence = None;
-        let mut vertices_evolution: HashMap<NodeIndex, Vec<Vec<usize>>> = HashMap::new();
+        let mut histogram_sum: Vec<f64> = vec![];
+        let mut node_count = 0;
+        let mut vertices_evolution: HashMap<usize, Vec<Vec<f64>>> = HashMap::new();
+        let mut metrics: Option<NetworkMetricsAccumulator> = None;
 
         for _ in 0..self.iteration_number {
-            let mut model: G = FromModelConfig::from_model_config(model_config);
-            let graph = model.generate();
-            for vid in model_config.tracked_timesteps {
-                vertices_evolution
-                    .entry(NodeIndex::new(*vid))
-                    .or_default()
-                    .push(model.get_vertex_evolution(NodeIndex::new(*vid)))
+            let mut model: G = FromModelConfig::from_model_config(model_config, rng);
+            let graph = model.generate(rng);
+            for arrival in model_config.tracked_arrivals {
+                if let Some(evolution) = model.get_arrival_evolution(arrival) {
+                    vertices_evolution
+                        .entry(*arrival)
+                        .or_default()
+                        .push(evolution);
+                }
             }
-            if sequence.is_none() {
-                sequence = Some(graph.degree_sequence());
+            if model_config.compute_network_metrics {
+                metrics = Some(match metrics {
+                    Some(acc) => acc.add(NetworkMetricsAccumulator::from_graph(&graph)),
+                    None => NetworkMetricsAccumulator::from_graph(&graph),
+                });
             }
+            let sequence = graph.degree_sequence();
+            node_count = sequence.len();
+            accumulate_histogram(&mut histogram_sum, &degree_histogram(&sequence));
         }
-        let meaned_vertices_evolution: HashMap<NodeIndex, Vec<usize>> = vertices_evolution
+        let meaned_vertices_evolution: HashMap<usize, Vec<f64>> = vertices_evolution
             .into_iter()
             .map(|(k, ce)| (k, Simulation::<Start>::mean_vectors(&ce)))
             .collect();
+        let iteration_number = self.iteration_number as f64;
+        let degree_distribution: Vec<f64> = histogram_sum
+            .into_iter()
+            .map(|sum| sum / iteration_number)
+            .collect();
+        let mean_degree_sequence = reconstruct_degree_sequence(&degree_distribution, node_count);
 
-        for k in meaned_vertices_evolution.keys() {
-            println!(
-                "Vertex : {:?}, vertices evolution len {:?}",
-                k,
-                meaned_vertices_evolution[k].len()
-            );
+        Simulation {
+            degree_sequence: Some(mean_degree_sequence),
+            iteration_number: self.iteration_number,
+            state: Over {
+                vertices_evolution: Some(meaned_vertices_evolution),
+                network_metrics: metrics.map(|acc| acc.into_mean(self.iteration_number)),
+                degree_distribution: Some(degree_distribution),
+            },
+        }
+    }
+
+    /// Opt-in parallel counterpart of `simulate_with_tracking`: each iteration builds an
+    /// independent model from the same `ModelConfig`, so the Monte Carlo loop is distributed
+    /// across threads with rayon and the per-worker histograms, metrics and per-tracked-vertex
+    /// evolutions are reduced afterwards, the same way the sequential loop folds them one by one.
+    /// Each worker seeds its own `R` deterministically from `seed + iteration index`, since a
+    /// single `&mut R` cannot be shared across threads.
+    pub fn simulate_parallel<G, R>(self, model_config: ModelConfig, seed: u64) -> Simulation<Over>
+    where
+        G: FromModelConfig<R> + Gen<R> + TrackVertices,
+        R: Rng + SeedableRng,
+    {
+        let worker_results: Vec<_> = (0..self.iteration_number)
+            .into_par_iter()
+            .map(|i| {
+                let mut rng = R::seed_from_u64(seed.wrapping_add(i as u64));
+                let mut model: G = FromModelConfig::from_model_config(model_config, &mut rng);
+                let graph = model.generate(&mut rng);
+
+                let sequence = graph.degree_sequence();
+                let histogram = degree_histogram(&sequence);
+                let metrics = model_config
+                    .compute_network_metrics
+                    .then(|| NetworkMetricsAccumulator::from_graph(&graph));
+                let arrival_evolutions: HashMap<usize, Vec<f64>> = model_config
+                    .tracked_arrivals
+                    .iter()
+                    .filter_map(|arrival| {
+                        model
+                            .get_arrival_evolution(arrival)
+                            .map(|evolution| (*arrival, evolution))
+                    })
+                    .collect();
+
+                (histogram, sequence.len(), metrics, arrival_evolutions)
+            })
+            .collect();
+
+        let mut histogram_sum: Vec<f64> = vec![];
+        let mut node_count = 0;
+        let mut metrics: Option<NetworkMetricsAccumulator> = None;
+        let mut vertices_evolution: HashMap<usize, Vec<Vec<f64>>> = HashMap::new();
+
+        for (histogram, worker_node_count, worker_metrics, arrival_evolutions) in worker_results {
+            accumulate_histogram(&mut histogram_sum, &histogram);
+            node_count = worker_node_count;
+            if let Some(worker_metrics) = worker_metrics {
+                metrics = Some(match metrics {
+                    Some(acc) => acc.add(worker_metrics),
+                    None => worker_metrics,
+                });
+            }
+            for (arrival, evolution) in arrival_evolutions {
+                vertices_evolution
+                    .entry(arrival)
+                    .or_default()
+                    .push(evolution);
+            }
         }
 
+        let meaned_vertices_evolution: HashMap<usize, Vec<f64>> = vertices_evolution
+            .into_iter()
+            .map(|(k, ce)| (k, Simulation::<Start>::mean_vectors(&ce)))
+            .collect();
+        let iteration_number = self.iteration_number as f64;
+        let degree_distribution: Vec<f64> = histogram_sum
+            .into_iter()
+            .map(|sum| sum / iteration_number)
+            .collect();
+        let mean_degree_sequence = reconstruct_degree_sequence(&degree_distribution, node_count);
+
         Simulation {
-            degree_sequence: sequence,
+            degree_sequence: Some(mean_degree_sequence),
             iteration_number: self.iteration_number,
             state: Over {
                 vertices_evolution: Some(meaned_vertices_evolution),
+                network_metrics: metrics.map(|acc| acc.into_mean(self.iteration_number)),
+                degree_distribution: Some(degree_distribution),
             },
         }
     }
@@ -128,10 +338,230 @@ impl Simulation<Over> {
         unreachable!("Type state pattern prevent degree sequence being None")
     }
 
-    pub fn get_vertex_evolution<G: TrackVertices>(&self) -> HashMap<NodeIndex, Vec<usize>> {
+    pub fn get_mean_degree_distribution(&self) -> Vec<f64> {
+        if let Some(distribution) = &self.state.degree_distribution {
+            return distribution.clone();
+        }
+        unreachable!("Type state pattern prevent degree distribution from being None")
+    }
+
+    pub fn get_mean_arrival_evolution<G: TrackVertices>(&self) -> HashMap<usize, Vec<f64>> {
         if let Some(ve) = &self.state.vertices_evolution {
             return ve.clone();
         }
         unreachable!("Type state pattern prevent vertex evolution from being None")
     }
+
+    /// `None` unless `ModelConfig::compute_network_metrics` was set: network metrics are an
+    /// opt-in, all-pairs-shortest-path pass, not something every simulation run pays for.
+    pub fn get_mean_metrics(&self) -> Option<MeanNetworkMetrics> {
+        self.state.network_metrics
+    }
+
+    /// Fit the discrete power-law exponent on the mean degree sequence, returning
+    /// `(k_min, gamma, sigma)`: the lower cutoff, the scaling exponent, and its standard error.
+    pub fn get_power_law_exponent(&self) -> Option<(usize, f64, f64)> {
+        let fit = fit_power_law(&self.get_mean_degree_sequence())?;
+        Some((fit.k_min, fit.alpha, fit.standard_error))
+    }
+
+    /// Serialize the whole result (degree sequence, distribution, network metrics and tracked
+    /// vertex evolutions) to pretty-printed JSON, so a run can be reloaded by external tooling
+    /// without re-running the simulation.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Render the degree distribution and tracked vertex evolutions as a single CSV table,
+    /// one row per position `0..max(distribution.len(), evolution lengths)`: the `degree` column
+    /// doubles as the distribution's degree bin and as the evolutions' time step, since the two
+    /// are indexed by different quantities but both start at zero.
+    #[cfg(feature = "serde")]
+    pub fn to_csv(&self) -> String {
+        let distribution = self.get_mean_degree_distribution();
+        let mut arrivals: Vec<usize> = self
+            .state
+            .vertices_evolution
+            .as_ref()
+            .map(|ve| ve.keys().copied().collect())
+            .unwrap_or_default();
+        arrivals.sort_unstable();
+
+        let row_count = arrivals
+            .iter()
+            .filter_map(|arrival| self.state.vertices_evolution.as_ref()?.get(arrival))
+            .map(|evolution| evolution.len())
+            .chain(std::iter::once(distribution.len()))
+            .max()
+            .unwrap_or(0);
+
+        let mut header = vec!["degree".to_string(), "distribution".to_string()];
+        header.extend(arrivals.iter().map(|arrival| format!("arrival_{arrival}")));
+        let mut csv = header.join(",");
+        csv.push('\n');
+
+        for row in 0..row_count {
+            let mut fields = vec![row.to_string()];
+            fields.push(
+                distribution
+                    .get(row)
+                    .map(|v| v.to_string())
+                    .unwrap_or_default(),
+            );
+            for arrival in &arrivals {
+                let value = self
+                    .state
+                    .vertices_evolution
+                    .as_ref()
+                    .and_then(|ve| ve.get(arrival))
+                    .and_then(|evolution| evolution.get(row))
+                    .map(|v| v.to_string())
+                    .unwrap_or_default();
+                fields.push(value);
+            }
+            csv.push_str(&fields.join(","));
+            csv.push('\n');
+        }
+
+        csv
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod test {
+    use std::collections::HashMap;
+
+    use super::{MeanNetworkMetrics, Over, Simulation};
+
+    fn over_with(
+        degree_distribution: Vec<f64>,
+        vertices_evolution: Option<HashMap<usize, Vec<f64>>>,
+    ) -> Simulation<Over> {
+        Simulation {
+            iteration_number: 1,
+            degree_sequence: Some(vec![]),
+            state: Over {
+                vertices_evolution,
+                network_metrics: Some(MeanNetworkMetrics {
+                    average_clustering_coefficient: 0.5,
+                    connected_components_count: 1.0,
+                    average_shortest_path_length: 1.5,
+                    diameter: 2.0,
+                }),
+                degree_distribution: Some(degree_distribution),
+            },
+        }
+    }
+
+    #[test]
+    fn test_to_json_round_trips_mean_metrics() {
+        let simulation = over_with(vec![0.5, 0.5], None);
+
+        let json = simulation.to_json().expect("serialization should succeed");
+        let parsed: serde_json::Value =
+            serde_json::from_str(&json).expect("output should be valid JSON");
+
+        assert_eq!(
+            parsed["network_metrics"]["diameter"].as_f64(),
+            Some(2.0)
+        );
+        assert_eq!(
+            parsed["degree_distribution"],
+            serde_json::json!([0.5, 0.5])
+        );
+    }
+
+    #[test]
+    fn test_to_csv_header_lists_distribution_then_sorted_arrivals() {
+        let mut vertices_evolution = HashMap::new();
+        vertices_evolution.insert(10, vec![1.0, 2.0]);
+        vertices_evolution.insert(1, vec![0.0]);
+        let simulation = over_with(vec![0.1, 0.2], Some(vertices_evolution));
+
+        let csv = simulation.to_csv();
+        let header = csv.lines().next().unwrap();
+
+        assert_eq!(header, "degree,distribution,arrival_1,arrival_10");
+    }
+
+    #[test]
+    fn test_to_csv_pads_shorter_columns_with_empty_fields() {
+        let mut vertices_evolution = HashMap::new();
+        // Distribution has 2 entries, the tracked arrival's evolution has 3: the longer one
+        // sets the row count and the shorter columns trail off with empty fields.
+        vertices_evolution.insert(1, vec![0.0, 1.0, 2.0]);
+        let simulation = over_with(vec![0.4, 0.6], Some(vertices_evolution));
+
+        let csv = simulation.to_csv();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next().unwrap(), "degree,distribution,arrival_1");
+        assert_eq!(lines.next().unwrap(), "0,0.4,0");
+        assert_eq!(lines.next().unwrap(), "1,0.6,1");
+        assert_eq!(lines.next().unwrap(), "2,,2");
+        assert_eq!(lines.next(), None);
+    }
+
+    #[test]
+    fn test_to_csv_no_tracked_vertices_is_distribution_only() {
+        let simulation = over_with(vec![1.0], None);
+
+        let csv = simulation.to_csv();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next().unwrap(), "degree,distribution");
+        assert_eq!(lines.next().unwrap(), "0,1");
+        assert_eq!(lines.next(), None);
+    }
+}
+
+#[cfg(test)]
+mod parallel_test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::Simulation;
+    use crate::{
+        models::{BarabasiAlbertClassic, GraphType, ModelConfig},
+        vertices_evolution::TrackMetric,
+    };
+
+    const CONFIG: ModelConfig = ModelConfig {
+        initial_nodes: 5,
+        edges_increment: 2,
+        end_time: 8,
+        starting_graph_type: GraphType::Complete,
+        tracked_arrivals: &[1, 4],
+        track_metric: TrackMetric::Degree,
+        compute_network_metrics: false,
+    };
+
+    #[test]
+    fn test_simulate_parallel_agrees_with_sequential_on_aggregate_shape() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let sequential = Simulation::new(4)
+            .simulate_with_tracking::<BarabasiAlbertClassic, _>(CONFIG, &mut rng);
+        let parallel = Simulation::new(4)
+            .simulate_parallel::<BarabasiAlbertClassic, StdRng>(CONFIG, 7);
+
+        assert_eq!(
+            sequential.get_mean_degree_distribution().len(),
+            parallel.get_mean_degree_distribution().len(),
+            "both runs should reach the same maximum degree bucket count"
+        );
+
+        let mut sequential_arrivals: Vec<usize> = sequential
+            .get_mean_arrival_evolution::<BarabasiAlbertClassic>()
+            .into_keys()
+            .collect();
+        let mut parallel_arrivals: Vec<usize> = parallel
+            .get_mean_arrival_evolution::<BarabasiAlbertClassic>()
+            .into_keys()
+            .collect();
+        sequential_arrivals.sort_unstable();
+        parallel_arrivals.sort_unstable();
+
+        assert_eq!(
+            sequential_arrivals, parallel_arrivals,
+            "both runs should track the same tracked-arrival vertices"
+        );
+    }
 }
@@ -1,24 +1,44 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use petgraph::graph::{NodeIndex, UnGraph};
 
+/// A per-vertex metric a `VertexTracker` can record at each simulation timestep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TrackMetric {
+    /// Number of neighbors of the tracked vertex.
+    Degree,
+    /// Local clustering coefficient `c_i = 2 * (#edges among neighbors) / (k_i * (k_i - 1))`.
+    ClusteringCoefficient,
+    /// Size of the connected component the tracked vertex currently belongs to.
+    ComponentSize,
+}
+
 pub trait TrackVertices {
-    fn get_arrival_evolution(&self, arrival_time: &usize) -> Option<Vec<usize>>;
+    fn get_arrival_evolution(&self, arrival_time: &usize) -> Option<Vec<f64>>;
     fn update(&mut self, graph: &UnGraph<(), ()>, time: usize);
 }
 
+/// Records the evolution of a single, chosen `TrackMetric` for a set of tracked vertices over
+/// simulation time, so the three Barabasi-Albert models can share one tracking implementation
+/// instead of each recording `graph.neighbors(node).count()` by hand.
 #[derive(Clone)]
-pub struct VerticesEvolution {
-    /// List of effective tracked vertices index
-    tracked_vertices: Vec<NodeIndex>,
-    vertices_evolution: HashMap<NodeIndex, Vec<usize>>,
+pub struct VertexTracker {
+    metric: TrackMetric,
+    /// List of effective tracked vertices, alongside the time step they arrive in the network:
+    /// the `NodeIndex` is unrelated to the arrival time (offset by `initial_nodes` in the growth
+    /// models, a randomly picked existing vertex in the no-growth model), so the two cannot be
+    /// conflated when deciding when to start recording.
+    tracked_vertices: Vec<(usize, NodeIndex)>,
+    vertices_evolution: HashMap<NodeIndex, Vec<f64>>,
     /// Map the arrival time in the graph with the corresponding NodeIndex in the graph
     arrival_map: HashMap<usize, NodeIndex>,
 }
 
-impl VerticesEvolution {
-    pub fn new() -> Self {
+impl VertexTracker {
+    pub fn new(metric: TrackMetric) -> Self {
         Self {
+            metric,
             tracked_vertices: vec![],
             vertices_evolution: HashMap::new(),
             arrival_map: HashMap::new(),
@@ -26,38 +46,138 @@ impl VerticesEvolution {
     }
 
     pub fn track_vertex(&mut self, arrival: usize, vertex: NodeIndex) {
-        self.tracked_vertices.push(vertex);
+        self.tracked_vertices.push((arrival, vertex));
         self.arrival_map.insert(arrival, vertex);
     }
 }
 
-impl Default for VerticesEvolution {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl TrackVertices for VerticesEvolution {
-    fn get_arrival_evolution(&self, arrival_time: &usize) -> Option<Vec<usize>> {
-        if !self.arrival_map.contains_key(arrival_time) {
-            return None;
-        }
-        let node = self.arrival_map.get(arrival_time).unwrap();
+impl TrackVertices for VertexTracker {
+    fn get_arrival_evolution(&self, arrival_time: &usize) -> Option<Vec<f64>> {
+        let node = self.arrival_map.get(arrival_time)?;
         self.vertices_evolution.get(node).cloned()
     }
 
     fn update(&mut self, graph: &UnGraph<(), ()>, time: usize) {
-        for vertex in &self.tracked_vertices {
+        for &(arrival, vertex) in &self.tracked_vertices {
             // Only start updating the node degree evolution if we are at least at time step where
             // he arrive
-            //VERIFIER CELA POUR LE MODELE NO GROWTH
-            if vertex.index() > time {
+            if arrival > time {
                 continue;
             }
+            let value = compute_metric(self.metric, graph, vertex);
             self.vertices_evolution
-                .entry(*vertex)
+                .entry(vertex)
                 .or_default()
-                .push(graph.neighbors(*vertex).count())
+                .push(value)
+        }
+    }
+}
+
+fn compute_metric(metric: TrackMetric, graph: &UnGraph<(), ()>, vertex: NodeIndex) -> f64 {
+    match metric {
+        TrackMetric::Degree => graph.neighbors(vertex).count() as f64,
+        TrackMetric::ClusteringCoefficient => local_clustering_coefficient(graph, vertex),
+        TrackMetric::ComponentSize => component_size(graph, vertex) as f64,
+    }
+}
+
+fn local_clustering_coefficient(graph: &UnGraph<(), ()>, vertex: NodeIndex) -> f64 {
+    let neighbors: Vec<NodeIndex> = graph.neighbors(vertex).collect();
+    let k = neighbors.len();
+    if k < 2 {
+        return 0.0;
+    }
+
+    let mut links = 0usize;
+    for i in 0..neighbors.len() {
+        for j in (i + 1)..neighbors.len() {
+            if graph.find_edge(neighbors[i], neighbors[j]).is_some() {
+                links += 1;
+            }
+        }
+    }
+
+    (2 * links) as f64 / (k * (k - 1)) as f64
+}
+
+fn component_size(graph: &UnGraph<(), ()>, vertex: NodeIndex) -> usize {
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+    visited.insert(vertex);
+    queue.push_back(vertex);
+
+    while let Some(current) = queue.pop_front() {
+        for neighbor in graph.neighbors(current) {
+            if visited.insert(neighbor) {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    visited.len()
+}
+
+#[cfg(test)]
+mod test {
+    use petgraph::graph::{NodeIndex, UnGraph};
+
+    use super::{TrackMetric, TrackVertices, VertexTracker};
+
+    #[test]
+    fn test_vertex_tracker_degree_evolution() {
+        let mut graph: UnGraph<(), ()> = UnGraph::new_undirected();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+
+        let mut tracker = VertexTracker::new(TrackMetric::Degree);
+        tracker.track_vertex(0, a);
+
+        tracker.update(&graph, 0);
+        graph.add_edge(a, b, ());
+        tracker.update(&graph, 1);
+
+        assert_eq!(tracker.get_arrival_evolution(&0), Some(vec![0.0, 1.0]));
+    }
+
+    #[test]
+    fn test_vertex_tracker_gates_on_arrival_time_not_node_index() {
+        // `initial_nodes = 5`: the vertex arriving at time 1 gets NodeIndex(5), so a tracker
+        // gating on `vertex.index() > time` would wait until time 5 instead of time 1.
+        let mut graph: UnGraph<(), ()> = UnGraph::new_undirected();
+        for _ in 0..5 {
+            graph.add_node(());
         }
+        let arriving = graph.add_node(());
+
+        let mut tracker = VertexTracker::new(TrackMetric::Degree);
+        tracker.track_vertex(1, arriving);
+
+        tracker.update(&graph, 1);
+        graph.add_edge(arriving, NodeIndex::new(0), ());
+        tracker.update(&graph, 2);
+
+        assert_eq!(tracker.get_arrival_evolution(&1), Some(vec![0.0, 1.0]));
+    }
+
+    #[test]
+    fn test_vertex_tracker_ignores_untracked_vertex() {
+        let graph: UnGraph<(), ()> = UnGraph::new_undirected();
+        let tracker = VertexTracker::new(TrackMetric::Degree);
+        assert_eq!(tracker.get_arrival_evolution(&0), None);
+    }
+
+    #[test]
+    fn test_vertex_tracker_component_size() {
+        let mut graph: UnGraph<(), ()> = UnGraph::new_undirected();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let _isolated = graph.add_node(());
+        graph.add_edge(a, b, ());
+
+        let mut tracker = VertexTracker::new(TrackMetric::ComponentSize);
+        tracker.track_vertex(0, a);
+        tracker.update(&graph, 0);
+
+        assert_eq!(tracker.get_arrival_evolution(&0), Some(vec![2.0]));
     }
 }